@@ -1,8 +1,12 @@
 use std::{
     ffi::{c_char, c_int, c_uint, c_void},
     marker::PhantomData,
+    time::Duration,
 };
 
+/// Microsecond timestamp type used by PulseAudio's C API
+type PaUsecT = u64;
+
 /// Raw handle to the pulseaudio simple api object
 type PaSimpleRaw = *mut c_void;
 
@@ -13,6 +17,24 @@ struct PaSampleSpecRaw {
     channels: c_char,
 }
 
+/// `PA_CHANNELS_MAX` from `pulse/sample.h`
+const PA_CHANNELS_MAX: usize = 32;
+
+#[repr(C)]
+struct PaChannelMapRaw {
+    channels: u8,
+    map: [c_int; PA_CHANNELS_MAX],
+}
+
+#[repr(C)]
+struct PaBufferAttrRaw {
+    maxlength: c_uint,
+    tlength: c_uint,
+    prebuf: c_uint,
+    minreq: c_uint,
+    fragsize: c_uint,
+}
+
 #[link(name = "pulse-simple")]
 unsafe extern "C" {
     fn pa_simple_new(
@@ -27,7 +49,10 @@ unsafe extern "C" {
         err: *mut c_int,
     ) -> PaSimpleRaw;
     fn pa_simple_write(simple: PaSimpleRaw, data: *const c_void, len: usize, err: *mut c_int);
+    fn pa_simple_read(simple: PaSimpleRaw, data: *mut c_void, len: usize, err: *mut c_int) -> c_int;
     fn pa_simple_drain(simple: PaSimpleRaw, err: *mut c_int);
+    fn pa_simple_get_latency(simple: PaSimpleRaw, err: *mut c_int) -> PaUsecT;
+    fn pa_simple_flush(simple: PaSimpleRaw, err: *mut c_int);
     fn pa_simple_free(simple: PaSimpleRaw);
 }
 
@@ -36,24 +61,127 @@ unsafe extern "C" {
     fn pa_strerror(err: c_int) -> *mut c_char;
 }
 
-/// Internal convenience function for error strings
+/// Internal convenience function for error strings. `pa_strerror` returns a pointer to a
+/// string PulseAudio still owns, so this copies it via `CStr` rather than taking ownership
+/// with `CString::from_raw`
 fn err_to_string(err: c_int) -> String {
-    unsafe {
-        std::ffi::CString::from_raw(pa_strerror(err))
-            .to_string_lossy()
-            .to_string()
+    unsafe { std::ffi::CStr::from_ptr(pa_strerror(err)) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Well-known PulseAudio error codes, mirroring `pa_error_code_t`
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaErrorCode {
+    AccessDenied = 1,
+    Command = 2,
+    InvalidArgument = 3,
+    Exist = 4,
+    NoEntity = 5,
+    ConnectionRefused = 6,
+    Protocol = 7,
+    Timeout = 8,
+    AuthKey = 9,
+    Internal = 10,
+    ConnectionTerminated = 11,
+    Killed = 12,
+    InvalidServer = 13,
+    ModuleInitFailed = 14,
+    BadState = 15,
+    NoData = 16,
+    Version = 17,
+    TooLarge = 18,
+    NotSupported = 19,
+    Unknown = 20,
+    NoExtension = 21,
+    Obsolete = 22,
+    NotImplemented = 23,
+    Forked = 24,
+    Io = 25,
+    Busy = 26,
+}
+
+impl PaErrorCode {
+    fn from_raw(code: c_int) -> Option<Self> {
+        Some(match code {
+            1 => Self::AccessDenied,
+            2 => Self::Command,
+            3 => Self::InvalidArgument,
+            4 => Self::Exist,
+            5 => Self::NoEntity,
+            6 => Self::ConnectionRefused,
+            7 => Self::Protocol,
+            8 => Self::Timeout,
+            9 => Self::AuthKey,
+            10 => Self::Internal,
+            11 => Self::ConnectionTerminated,
+            12 => Self::Killed,
+            13 => Self::InvalidServer,
+            14 => Self::ModuleInitFailed,
+            15 => Self::BadState,
+            16 => Self::NoData,
+            17 => Self::Version,
+            18 => Self::TooLarge,
+            19 => Self::NotSupported,
+            20 => Self::Unknown,
+            21 => Self::NoExtension,
+            22 => Self::Obsolete,
+            23 => Self::NotImplemented,
+            24 => Self::Forked,
+            25 => Self::Io,
+            26 => Self::Busy,
+            _ => return None,
+        })
+    }
+}
+
+/// Error returned by this crate's PulseAudio bindings. Wraps the raw `c_int` PulseAudio error
+/// code and lazily formats the human-readable message via `pa_strerror` on demand
+#[derive(Debug, Clone, Copy)]
+pub struct PaError {
+    code: c_int,
+}
+
+impl PaError {
+    fn new(code: c_int) -> Self {
+        Self { code }
+    }
+
+    /// The raw error code as returned by the PulseAudio C API
+    pub fn code(&self) -> c_int {
+        self.code
+    }
+
+    /// The well-known error kind this code corresponds to, if recognized
+    pub fn kind(&self) -> Option<PaErrorCode> {
+        PaErrorCode::from_raw(self.code)
+    }
+}
+
+impl std::fmt::Display for PaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", err_to_string(self.code))
     }
 }
 
+impl std::error::Error for PaError {}
+
 #[repr(i32)]
 enum RawSample {
     U8 = 0,
+    Alaw = 1,
+    Ulaw = 2,
     S16LE = 3,
     S16BE = 4,
     FLOAT32LE = 5,
     FLOAT32BE = 6,
     S32LE = 7,
     S32BE = 8,
+    S24LE = 9,
+    S24BE = 10,
+    S24In32LE = 11,
+    S24In32BE = 12,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -71,6 +199,92 @@ impl StreamDirection {
     }
 }
 
+/// Position of a single channel within a `ChannelMap`, mirroring a subset of
+/// `pa_channel_position_t`
+#[repr(i32)]
+#[derive(Debug, Clone, Copy)]
+pub enum ChannelPosition {
+    Mono = 0,
+    FrontLeft = 1,
+    FrontRight = 2,
+    FrontCenter = 3,
+    RearCenter = 4,
+    RearLeft = 5,
+    RearRight = 6,
+    Lfe = 7,
+    SideLeft = 10,
+    SideRight = 11,
+}
+
+/// Maps logical channels (as written/read) onto physical speaker positions
+#[derive(Debug, Clone)]
+pub struct ChannelMap {
+    positions: Vec<ChannelPosition>,
+}
+
+impl ChannelMap {
+    /// Build a channel map from explicit positions. Panics if more than `PA_CHANNELS_MAX` (32)
+    /// positions are given
+    pub fn new(positions: Vec<ChannelPosition>) -> Self {
+        assert!(positions.len() <= PA_CHANNELS_MAX);
+        Self { positions }
+    }
+
+    pub fn mono() -> Self {
+        Self::new(vec![ChannelPosition::Mono])
+    }
+
+    pub fn stereo() -> Self {
+        Self::new(vec![ChannelPosition::FrontLeft, ChannelPosition::FrontRight])
+    }
+
+    fn into_c(self) -> PaChannelMapRaw {
+        let mut map = [0; PA_CHANNELS_MAX];
+        for (slot, position) in map.iter_mut().zip(self.positions.iter()) {
+            *slot = *position as c_int;
+        }
+        PaChannelMapRaw {
+            channels: self.positions.len() as u8,
+            map,
+        }
+    }
+}
+
+/// Mirrors `pa_buffer_attr`, controlling the latency/robustness tradeoff of a stream. Fields
+/// left as `u32::MAX` (the default) tell PulseAudio to pick a sensible value itself
+#[derive(Debug, Clone, Copy)]
+pub struct BufferAttr {
+    pub maxlength: u32,
+    pub tlength: u32,
+    pub prebuf: u32,
+    pub minreq: u32,
+    pub fragsize: u32,
+}
+
+impl Default for BufferAttr {
+    fn default() -> Self {
+        Self {
+            maxlength: u32::MAX,
+            tlength: u32::MAX,
+            prebuf: u32::MAX,
+            minreq: u32::MAX,
+            fragsize: u32::MAX,
+        }
+    }
+}
+
+impl BufferAttr {
+    fn into_c(self) -> PaBufferAttrRaw {
+        PaBufferAttrRaw {
+            maxlength: self.maxlength,
+            tlength: self.tlength,
+            prebuf: self.prebuf,
+            minreq: self.minreq,
+            fragsize: self.fragsize,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SampleSpec {
     rate: u32,
@@ -93,11 +307,39 @@ impl SampleSpec {
 #[derive(Debug)]
 pub struct Simple<T: PSimple> {
     raw_handle: PaSimpleRaw,
+    /// Leftover bytes from `write_bytes` that don't yet complete a whole sample, carried over
+    /// to the next call instead of being dropped. Lazily allocated: stays empty (and
+    /// allocation-free) for callers who only ever use `write`/`write_single`
+    pending: Vec<u8>,
     _dont: PhantomData<T>,
 }
 
 pub trait PSimple: Copy + 'static {
     const FORMAT: c_int;
+
+    /// Number of bytes a single sample occupies on the wire. Defaults to `size_of::<Self>()`;
+    /// packed formats like 24-bit PCM override this since their in-memory representation is
+    /// wider than their wire representation
+    const WIRE_SIZE: usize = std::mem::size_of::<Self>();
+
+    /// Append this sample's wire-format bytes to `buf`. The default reinterprets the sample's
+    /// own memory, which is only correct when `WIRE_SIZE == size_of::<Self>()`; packed formats
+    /// like `S24` override this to pick the wire bytes out of the value explicitly instead of
+    /// assuming anything about host byte order
+    fn append_wire_bytes(&self, buf: &mut Vec<u8>) {
+        debug_assert_eq!(Self::WIRE_SIZE, std::mem::size_of::<Self>());
+        let raw =
+            unsafe { std::slice::from_raw_parts(self as *const Self as *const u8, Self::WIRE_SIZE) };
+        buf.extend_from_slice(raw);
+    }
+
+    /// Reconstruct a sample from exactly `WIRE_SIZE` wire-format bytes. See
+    /// [`PSimple::append_wire_bytes`] for the same default-vs-packed-format caveat
+    fn from_wire_bytes(bytes: &[u8]) -> Self {
+        debug_assert_eq!(bytes.len(), Self::WIRE_SIZE);
+        debug_assert_eq!(Self::WIRE_SIZE, std::mem::size_of::<Self>());
+        unsafe { std::ptr::read_unaligned(bytes.as_ptr() as *const Self) }
+    }
 }
 
 impl PSimple for u8 {
@@ -125,52 +367,194 @@ impl PSimple for i32 {
     const FORMAT: c_int = RawSample::S32BE as c_int;
 }
 
-impl<T: PSimple> Simple<T> {
-    /// Create a new simple pulseaudio object
-    pub fn new(
-        stream_name: &str,
-        direction: StreamDirection,
-        sample_spec: SampleSpec,
-    ) -> Result<Self, String> {
-        let c_string = std::ffi::CString::new(stream_name).expect("Failed to create CString");
+/// 8-bit A-law encoded sample (ITU-T G.711), used by telephony-grade audio
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy)]
+pub struct Alaw(pub u8);
+
+impl PSimple for Alaw {
+    const FORMAT: c_int = RawSample::Alaw as c_int;
+}
+
+/// 8-bit mu-law encoded sample (ITU-T G.711), used by telephony-grade audio
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy)]
+pub struct Ulaw(pub u8);
+
+impl PSimple for Ulaw {
+    const FORMAT: c_int = RawSample::Ulaw as c_int;
+}
+
+/// Packed 24-bit PCM sample. Held in the low 3 bytes of an `i32` in native byte order, but
+/// only those 3 bytes are ever put on the wire
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy)]
+pub struct S24(pub i32);
+
+impl PSimple for S24 {
+    #[cfg(target_endian = "little")]
+    const FORMAT: c_int = RawSample::S24LE as c_int;
+    #[cfg(target_endian = "big")]
+    const FORMAT: c_int = RawSample::S24BE as c_int;
+
+    const WIRE_SIZE: usize = 3;
+
+    fn append_wire_bytes(&self, buf: &mut Vec<u8>) {
+        let v = self.0;
+        #[cfg(target_endian = "little")]
+        buf.extend_from_slice(&[v as u8, (v >> 8) as u8, (v >> 16) as u8]);
+        #[cfg(target_endian = "big")]
+        buf.extend_from_slice(&[(v >> 16) as u8, (v >> 8) as u8, v as u8]);
+    }
+
+    fn from_wire_bytes(bytes: &[u8]) -> Self {
+        #[cfg(target_endian = "little")]
+        let v = (bytes[0] as i32) | ((bytes[1] as i32) << 8) | ((bytes[2] as i8 as i32) << 16);
+        #[cfg(target_endian = "big")]
+        let v = ((bytes[0] as i8 as i32) << 16) | ((bytes[1] as i32) << 8) | (bytes[2] as i32);
+        Self(v)
+    }
+}
+
+/// 24-bit PCM sample occupying a full 32-bit word on the wire, unlike the packed [`S24`]
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy)]
+pub struct S24In32(pub i32);
+
+impl PSimple for S24In32 {
+    #[cfg(target_endian = "little")]
+    const FORMAT: c_int = RawSample::S24In32LE as c_int;
+    #[cfg(target_endian = "big")]
+    const FORMAT: c_int = RawSample::S24In32BE as c_int;
+}
+
+/// Builder for [`Simple`], letting callers pick a specific server/device, channel map or
+/// buffer attributes instead of accepting PulseAudio's defaults
+#[derive(Debug)]
+pub struct SimpleBuilder<T: PSimple> {
+    stream_name: String,
+    direction: StreamDirection,
+    sample_spec: SampleSpec,
+    server: Option<String>,
+    device: Option<String>,
+    channel_map: Option<ChannelMap>,
+    buffer_attr: Option<BufferAttr>,
+    _dont: PhantomData<T>,
+}
+
+impl<T: PSimple> SimpleBuilder<T> {
+    pub fn new(stream_name: &str, direction: StreamDirection, sample_spec: SampleSpec) -> Self {
+        Self {
+            stream_name: stream_name.to_string(),
+            direction,
+            sample_spec,
+            server: None,
+            device: None,
+            channel_map: None,
+            buffer_attr: None,
+            _dont: PhantomData::default(),
+        }
+    }
 
-        let mut sam_raw = sample_spec.into_c();
+    /// Connect to a specific PulseAudio server instead of the default one
+    pub fn server(mut self, server: &str) -> Self {
+        self.server = Some(server.to_string());
+        self
+    }
+
+    /// Use a specific sink/source instead of the default one
+    pub fn device(mut self, device: &str) -> Self {
+        self.device = Some(device.to_string());
+        self
+    }
+
+    /// Map logical channels onto physical speaker positions
+    pub fn channel_map(mut self, channel_map: ChannelMap) -> Self {
+        self.channel_map = Some(channel_map);
+        self
+    }
+
+    /// Tune the buffering behaviour of the stream
+    pub fn buffer_attr(mut self, buffer_attr: BufferAttr) -> Self {
+        self.buffer_attr = Some(buffer_attr);
+        self
+    }
+
+    /// Create the underlying pulseaudio simple api object with the configured options
+    pub fn build(self) -> Result<Simple<T>, PaError> {
+        let c_string = std::ffi::CString::new(self.stream_name).expect("Failed to create CString");
+        let server_c_string = self
+            .server
+            .map(|s| std::ffi::CString::new(s).expect("Failed to create CString"));
+        let device_c_string = self
+            .device
+            .map(|d| std::ffi::CString::new(d).expect("Failed to create CString"));
+
+        let mut sam_raw = self.sample_spec.into_c();
         sam_raw.format = <T as PSimple>::FORMAT as i32;
 
-        let c_char_ptr: *const c_char = c_string.as_ptr();
+        let chan_map_raw = self.channel_map.map(ChannelMap::into_c);
+        let buffer_attr_raw = self.buffer_attr.map(BufferAttr::into_c);
 
         let mut err: c_int = 0;
 
         let handle = unsafe {
             pa_simple_new(
-                std::ptr::null_mut(),
-                std::ptr::null_mut(),
-                direction.into_c(),
-                std::ptr::null_mut(),
-                c_char_ptr,
+                server_c_string
+                    .as_ref()
+                    .map_or(std::ptr::null(), |s| s.as_ptr()),
+                std::ptr::null(),
+                self.direction.into_c(),
+                device_c_string
+                    .as_ref()
+                    .map_or(std::ptr::null(), |d| d.as_ptr()),
+                c_string.as_ptr(),
                 &sam_raw,
-                std::ptr::null_mut(),
-                std::ptr::null_mut(),
+                chan_map_raw.as_ref().map_or(std::ptr::null(), |m| {
+                    m as *const PaChannelMapRaw as *const c_void
+                }),
+                buffer_attr_raw.as_ref().map_or(std::ptr::null(), |a| {
+                    a as *const PaBufferAttrRaw as *const c_void
+                }),
                 &mut err,
             )
         };
         if err != 0 {
-            return Err(err_to_string(err));
+            return Err(PaError::new(err));
         }
-        Ok(Self {
+        Ok(Simple {
             raw_handle: handle,
+            pending: Vec::new(),
             _dont: PhantomData::default(),
         })
     }
+}
+
+impl<T: PSimple> Simple<T> {
+    /// Create a new simple pulseaudio object, connecting to the default server/device with
+    /// default buffering. Use [`SimpleBuilder`] to pick a specific server, device, channel map
+    /// or buffer attributes
+    pub fn new(
+        stream_name: &str,
+        direction: StreamDirection,
+        sample_spec: SampleSpec,
+    ) -> Result<Self, PaError> {
+        SimpleBuilder::new(stream_name, direction, sample_spec).build()
+    }
 
     /// Write `bytes.len()` number of samples to pulse
-    pub fn write(&mut self, bytes: &[T]) -> Result<(), String> {
-        fn as_bytes<T>(slice: &[T]) -> &[u8] {
-            unsafe {
-                std::slice::from_raw_parts_mut(
-                    slice.as_ptr() as *mut u8,
-                    slice.len() * std::mem::size_of::<T>(),
-                )
+    pub fn write(&mut self, bytes: &[T]) -> Result<(), PaError> {
+        fn as_bytes<T: PSimple>(slice: &[T]) -> std::borrow::Cow<'_, [u8]> {
+            if T::WIRE_SIZE == std::mem::size_of::<T>() {
+                std::borrow::Cow::Borrowed(unsafe {
+                    std::slice::from_raw_parts(slice.as_ptr() as *const u8, slice.len() * T::WIRE_SIZE)
+                })
+            } else {
+                let mut packed = Vec::with_capacity(slice.len() * T::WIRE_SIZE);
+                for sample in slice {
+                    sample.append_wire_bytes(&mut packed);
+                }
+                std::borrow::Cow::Owned(packed)
             }
         }
         let bytes = as_bytes(bytes);
@@ -179,32 +563,125 @@ impl<T: PSimple> Simple<T> {
         unsafe {
             pa_simple_write(
                 self.raw_handle,
-                bytes as *const [u8] as *const c_void,
+                bytes.as_ref() as *const [u8] as *const c_void,
                 bytes.len(),
                 &mut err,
             );
         }
         if err != 0 {
-            return Err(err_to_string(err));
+            return Err(PaError::new(err));
         }
 
         Ok(())
     }
 
     /// Write a single sample (discouraged?)
-    pub fn write_single(&mut self, b: T) -> Result<(), String> {
+    pub fn write_single(&mut self, b: T) -> Result<(), PaError> {
         self.write(&[b])
     }
 
+    /// Write a raw byte stream, e.g. PCM decoded by `hound` or `symphonia`, without requiring
+    /// the caller to already have it as `&[T]`. Bytes that don't complete a whole sample are
+    /// buffered and carried over to the next call rather than dropped
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), PaError> {
+        self.pending.extend_from_slice(bytes);
+
+        let sample_size = T::WIRE_SIZE;
+        let complete_len = self.pending.len() - (self.pending.len() % sample_size);
+        if complete_len == 0 {
+            return Ok(());
+        }
+
+        let mut err: c_int = 0;
+        unsafe {
+            pa_simple_write(
+                self.raw_handle,
+                self.pending.as_ptr() as *const c_void,
+                complete_len,
+                &mut err,
+            );
+        }
+        if err != 0 {
+            return Err(PaError::new(err));
+        }
+        self.pending.drain(..complete_len);
+
+        Ok(())
+    }
+
+    /// Read `buf.len()` number of samples from pulse, blocking until the whole buffer is
+    /// filled
+    pub fn read(&mut self, buf: &mut [T]) -> Result<(), PaError> {
+        if T::WIRE_SIZE == std::mem::size_of::<T>() {
+            let mut err: c_int = 0;
+            unsafe {
+                pa_simple_read(
+                    self.raw_handle,
+                    buf.as_mut_ptr() as *mut c_void,
+                    buf.len() * T::WIRE_SIZE,
+                    &mut err,
+                );
+            }
+            if err != 0 {
+                return Err(PaError::new(err));
+            }
+            return Ok(());
+        }
+
+        let mut raw = vec![0u8; buf.len() * T::WIRE_SIZE];
+        let mut err: c_int = 0;
+        unsafe {
+            pa_simple_read(
+                self.raw_handle,
+                raw.as_mut_ptr() as *mut c_void,
+                raw.len(),
+                &mut err,
+            );
+        }
+        if err != 0 {
+            return Err(PaError::new(err));
+        }
+
+        for (sample, chunk) in buf.iter_mut().zip(raw.chunks_exact(T::WIRE_SIZE)) {
+            *sample = T::from_wire_bytes(chunk);
+        }
+
+        Ok(())
+    }
+
+    /// Query the latency of the stream, i.e. how far behind (playback) or ahead (capture) the
+    /// audio actually played/recorded is from the data most recently written/read
+    pub fn latency(&self) -> Result<Duration, PaError> {
+        let mut err: c_int = 0;
+        let usec = unsafe { pa_simple_get_latency(self.raw_handle, &mut err) };
+        if err != 0 {
+            return Err(PaError::new(err));
+        }
+        Ok(Duration::from_micros(usec))
+    }
+
     /// Drain (wait until all data has been processed by pulseaudio) the pulseaudio simple api
     /// object
-    pub fn drain(&mut self) -> Result<(), String> {
+    pub fn drain(&mut self) -> Result<(), PaError> {
         let mut err: c_int = 0;
         unsafe {
             pa_simple_drain(self.raw_handle, &mut err);
         }
         if err != 0 {
-            return Err(err_to_string(err));
+            return Err(PaError::new(err));
+        }
+        Ok(())
+    }
+
+    /// Flush (discard any buffered data without waiting for it to be played/read) the
+    /// pulseaudio simple api object
+    pub fn flush(&mut self) -> Result<(), PaError> {
+        let mut err: c_int = 0;
+        unsafe {
+            pa_simple_flush(self.raw_handle, &mut err);
+        }
+        if err != 0 {
+            return Err(PaError::new(err));
         }
         Ok(())
     }
@@ -218,3 +695,32 @@ impl<T: PSimple> std::ops::Drop for Simple<T> {
         }
     }
 }
+
+impl std::io::Write for Simple<u8> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.write_bytes(buf).map_err(std::io::Error::other)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        // `write_bytes` always hands complete samples straight to PulseAudio, and for `u8`
+        // samples there's never a partial sample left to buffer, so there's nothing to do here
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn s24_wire_bytes_round_trip() {
+        for value in [0, 1, -1, 42, -42, 0x7F_FFFF, -0x80_0000, i32::MIN >> 8, i32::MAX >> 8] {
+            let sample = S24(value);
+            let mut bytes = Vec::new();
+            sample.append_wire_bytes(&mut bytes);
+            assert_eq!(bytes.len(), S24::WIRE_SIZE);
+            assert_eq!(S24::from_wire_bytes(&bytes).0, value);
+        }
+    }
+}